@@ -1,7 +1,7 @@
 use std::slice;
 use std::marker::PhantomData;
 use libc::{ c_short, c_char };
-use { ffi, Vector };
+use { ffi, Vector, Fixed, BBox };
 
 #[derive(Copy, Clone)]
 pub enum Curve {
@@ -44,6 +44,45 @@ impl<'a> Outline<'a> {
             ContourIterator::from_raw(self.raw)
         }
     }
+
+    /// Streams this outline's contours to `sink` as a sequence of
+    /// `move_to`/`line_to`/`quad_to`/`cubic_to`/`close` calls, one contour at
+    /// a time. See `OutlineSink` for why this exists alongside `Curve`.
+    pub fn decompose<S: OutlineSink>(&self, sink: &mut S) {
+        for curves in self.contours_iter() {
+            sink.move_to(curves.start());
+            for curve in curves {
+                match curve {
+                    Curve::Line(p) => sink.line_to(p),
+                    Curve::Bezier2(ctrl, p) => sink.quad_to(ctrl, p),
+                    Curve::Bezier3(c0, c1, p) => sink.cubic_to(c0, c1, p),
+                }
+            }
+            sink.close();
+        }
+    }
+
+    /// Returns an iterator that flattens each contour into a polyline,
+    /// subdividing curves until they are within `tolerance` font units of
+    /// the true curve.
+    pub fn flatten_iter(&self, tolerance: f32) -> FlattenIterator<'a> {
+        FlattenIterator {
+            contours: self.contours_iter(),
+            tolerance: tolerance,
+        }
+    }
+
+    /// Convenience wrapper around `flatten_iter` that collects every
+    /// contour's polyline into a `Vec`.
+    pub fn flatten(&self, tolerance: f32) -> Vec<Vec<Vector>> {
+        self.flatten_iter(tolerance).collect()
+    }
+
+    /// The winding direction of each contour, in order. Lets callers
+    /// implement correct even-odd vs. nonzero fills and detect holes.
+    pub fn contour_orientations(&self) -> Vec<Orientation> {
+        self.contours_iter().map(|curves| curves.orientation()).collect()
+    }
 }
 
 const TAG_MASK: c_char = 0x03;
@@ -59,6 +98,14 @@ fn middle_point(pt1: Vector, pt2: Vector) -> Vector {
     }
 }
 
+/// The winding direction of a contour.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+#[derive(Copy, Clone)]
 pub struct CurveIterator<'a> {
     start_point: *const Vector,
     start_tag: *const c_char,
@@ -163,6 +210,157 @@ impl<'a> Iterator for CurveIterator<'a> {
     }
 }
 
+impl<'a> CurveIterator<'a> {
+    /// This contour's signed area, via the shoelace formula accumulated
+    /// over its curve endpoints (including `start()`), closing back to the
+    /// start point. Curved segments are treated as straight chords, so the
+    /// result is exact for line-only contours and an approximation (not the
+    /// true curved area) otherwise. The sign, which is what `orientation()`
+    /// uses, is reliable for typical glyph contours; a pathological contour
+    /// whose curves bulge far enough to flip the chord polygon's winding
+    /// relative to the true curve could in principle flip it too. The sign
+    /// gives the winding direction (positive is counter-clockwise in font
+    /// coordinate space) and the magnitude is the (chord-approximated) area.
+    pub fn signed_area(&self) -> f64 {
+        let start = self.start();
+        let mut prev = start;
+        let mut sum = 0.0f64;
+        for curve in *self {
+            let next = match curve {
+                Curve::Line(p) => p,
+                Curve::Bezier2(_, p) => p,
+                Curve::Bezier3(_, _, p) => p,
+            };
+            sum += (prev.x as f64) * (next.y as f64) - (next.x as f64) * (prev.y as f64);
+            prev = next;
+        }
+        sum += (prev.x as f64) * (start.y as f64) - (start.x as f64) * (prev.y as f64);
+        sum / 2.0
+    }
+
+    /// This contour's winding direction, derived from the sign of `signed_area()`.
+    pub fn orientation(&self) -> Orientation {
+        if self.signed_area() >= 0.0 {
+            Orientation::CounterClockwise
+        } else {
+            Orientation::Clockwise
+        }
+    }
+}
+
+/// A consumer of the path commands produced by `Outline::decompose`.
+///
+/// This mirrors the push-style decomposition APIs found in other font and
+/// vector-graphics crates (e.g. lyon's `PathEvent`), giving downstream users
+/// a single integration point for building their own path representation
+/// without allocating an intermediate `Vec<Curve>`.
+pub trait OutlineSink {
+    /// Starts a new contour at `p`.
+    fn move_to(&mut self, p: Vector);
+    /// Adds a line segment ending at `p`.
+    fn line_to(&mut self, p: Vector);
+    /// Adds a quadratic Bezier segment with control point `ctrl`, ending at `p`.
+    fn quad_to(&mut self, ctrl: Vector, p: Vector);
+    /// Adds a cubic Bezier segment with control points `c0` and `c1`, ending at `p`.
+    fn cubic_to(&mut self, c0: Vector, c1: Vector, p: Vector);
+    /// Closes the current contour.
+    fn close(&mut self);
+}
+
+/// Maximum recursion depth for curve flattening, guarding against
+/// degenerate or pathological control points that would otherwise never
+/// converge to within tolerance.
+const FLATTEN_MAX_DEPTH: u32 = 16;
+
+#[inline]
+fn dist_sq(a: Vector, b: Vector) -> f64 {
+    let dx = (a.x - b.x) as f64;
+    let dy = (a.y - b.y) as f64;
+    dx * dx + dy * dy
+}
+
+#[inline]
+fn point_line_dist_sq(p: Vector, a: Vector, b: Vector) -> f64 {
+    let dx = (b.x - a.x) as f64;
+    let dy = (b.y - a.y) as f64;
+    let len_sq = dx * dx + dy * dy;
+    if len_sq == 0.0 {
+        return dist_sq(p, a);
+    }
+    let px = (p.x - a.x) as f64;
+    let py = (p.y - a.y) as f64;
+    let cross = px * dy - py * dx;
+    (cross * cross) / len_sq
+}
+
+fn flatten_quad(p0: Vector, c: Vector, p2: Vector, tolerance_sq: f64, depth: u32, out: &mut Vec<Vector>) {
+    let mid_chord = middle_point(p0, p2);
+    if depth >= FLATTEN_MAX_DEPTH || dist_sq(c, mid_chord) <= tolerance_sq {
+        out.push(p2);
+        return;
+    }
+    let p01 = middle_point(p0, c);
+    let p12 = middle_point(c, p2);
+    let p012 = middle_point(p01, p12);
+    flatten_quad(p0, p01, p012, tolerance_sq, depth + 1, out);
+    flatten_quad(p012, p12, p2, tolerance_sq, depth + 1, out);
+}
+
+fn flatten_cubic(p0: Vector, c0: Vector, c1: Vector, p3: Vector, tolerance_sq: f64, depth: u32, out: &mut Vec<Vector>) {
+    if depth >= FLATTEN_MAX_DEPTH ||
+       (point_line_dist_sq(c0, p0, p3) <= tolerance_sq && point_line_dist_sq(c1, p0, p3) <= tolerance_sq) {
+        out.push(p3);
+        return;
+    }
+    let p01 = middle_point(p0, c0);
+    let p12 = middle_point(c0, c1);
+    let p23 = middle_point(c1, p3);
+    let p012 = middle_point(p01, p12);
+    let p123 = middle_point(p12, p23);
+    let p0123 = middle_point(p012, p123);
+    flatten_cubic(p0, p01, p012, p0123, tolerance_sq, depth + 1, out);
+    flatten_cubic(p0123, p123, p23, p3, tolerance_sq, depth + 1, out);
+}
+
+/// Iterates over an outline's contours, flattening each one into a polyline
+/// of `Vector` points via recursive De Casteljau subdivision, so callers
+/// doing CPU rasterization or hit-testing don't need a full curve
+/// rasterizer. Lines pass through unchanged. Each polyline is closed
+/// explicitly: its last point is a duplicate of its first.
+pub struct FlattenIterator<'a> {
+    contours: ContourIterator<'a>,
+    tolerance: f32,
+}
+
+impl<'a> Iterator for FlattenIterator<'a> {
+    type Item = Vec<Vector>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let curves = self.contours.next()?;
+        let tolerance_sq = (self.tolerance as f64) * (self.tolerance as f64);
+        let start = curves.start();
+        let mut points = vec![start];
+        let mut current = start;
+        for curve in curves {
+            match curve {
+                Curve::Line(p) => {
+                    points.push(p);
+                    current = p;
+                },
+                Curve::Bezier2(ctrl, p) => {
+                    flatten_quad(current, ctrl, p, tolerance_sq, 0, &mut points);
+                    current = p;
+                },
+                Curve::Bezier3(c0, c1, p) => {
+                    flatten_cubic(current, c0, c1, p, tolerance_sq, 0, &mut points);
+                    current = p;
+                },
+            }
+        }
+        Some(points)
+    }
+}
+
 pub struct ContourIterator<'a> {
     outline: &'a ffi::FT_Outline,
     contour_start: c_short,
@@ -200,3 +398,486 @@ impl<'a> Iterator for ContourIterator<'a> {
         }
     }
 }
+
+#[inline]
+fn fixed_mul(f: Fixed, v: ffi::FT_Pos) -> ffi::FT_Pos {
+    (((f as i64) * (v as i64)) >> 16) as ffi::FT_Pos
+}
+
+/// Extends the crate's `BBox` (`ffi::FT_BBox`) with the construction and
+/// curve-extrema logic `OutlineBuf` needs. A plain trait impl, rather than a
+/// parallel type, since `BBox` already has the meaning "outline/glyph
+/// bounding box" elsewhere in the crate.
+trait BBoxExt {
+    fn empty() -> Self;
+    fn include(&mut self, p: Vector);
+    fn include_quad_extrema(&mut self, p0: Vector, c: Vector, p2: Vector);
+    fn include_cubic_extrema(&mut self, p0: Vector, c0: Vector, c1: Vector, p3: Vector);
+}
+
+impl BBoxExt for BBox {
+    fn empty() -> Self {
+        ffi::FT_BBox {
+            xMin: ffi::FT_Pos::MAX,
+            yMin: ffi::FT_Pos::MAX,
+            xMax: ffi::FT_Pos::MIN,
+            yMax: ffi::FT_Pos::MIN,
+        }
+    }
+
+    fn include(&mut self, p: Vector) {
+        if p.x < self.xMin { self.xMin = p.x; }
+        if p.x > self.xMax { self.xMax = p.x; }
+        if p.y < self.yMin { self.yMin = p.y; }
+        if p.y > self.yMax { self.yMax = p.y; }
+    }
+
+    fn include_quad_extrema(&mut self, p0: Vector, c: Vector, p2: Vector) {
+        if let Some(t) = quad_extremum_t(p0.x as i64, c.x as i64, p2.x as i64) {
+            let x = quad_eval(p0.x as i64, c.x as i64, p2.x as i64, t);
+            include_axis_extremum(&mut self.xMin, &mut self.xMax, x);
+        }
+        if let Some(t) = quad_extremum_t(p0.y as i64, c.y as i64, p2.y as i64) {
+            let y = quad_eval(p0.y as i64, c.y as i64, p2.y as i64, t);
+            include_axis_extremum(&mut self.yMin, &mut self.yMax, y);
+        }
+    }
+
+    fn include_cubic_extrema(&mut self, p0: Vector, c0: Vector, c1: Vector, p3: Vector) {
+        for t in cubic_extrema_ts(p0.x as f64, c0.x as f64, c1.x as f64, p3.x as f64) {
+            let x = cubic_eval(p0.x as f64, c0.x as f64, c1.x as f64, p3.x as f64, t);
+            include_axis_extremum(&mut self.xMin, &mut self.xMax, x);
+        }
+        for t in cubic_extrema_ts(p0.y as f64, c0.y as f64, c1.y as f64, p3.y as f64) {
+            let y = cubic_eval(p0.y as f64, c0.y as f64, c1.y as f64, p3.y as f64, t);
+            include_axis_extremum(&mut self.yMin, &mut self.yMax, y);
+        }
+    }
+}
+
+fn include_axis_extremum(min: &mut ffi::FT_Pos, max: &mut ffi::FT_Pos, v: f64) {
+    let v = v.round() as ffi::FT_Pos;
+    if v < *min { *min = v; }
+    if v > *max { *max = v; }
+}
+
+// Position (t) within [0, 1] where a quadratic Bezier's derivative is zero
+// on one axis, i.e. where that axis reaches an extremum.
+fn quad_extremum_t(p0: i64, c: i64, p2: i64) -> Option<f64> {
+    let denom = (p0 - 2 * c + p2) as f64;
+    if denom.abs() < 1e-9 {
+        return None;
+    }
+    let t = (p0 - c) as f64 / denom;
+    if t > 0.0 && t < 1.0 { Some(t) } else { None }
+}
+
+fn quad_eval(p0: i64, c: i64, p2: i64, t: f64) -> f64 {
+    let mt = 1.0 - t;
+    mt * mt * (p0 as f64) + 2.0 * mt * t * (c as f64) + t * t * (p2 as f64)
+}
+
+// Positions within [0, 1] where a cubic Bezier's derivative is zero on one
+// axis, found by solving the quadratic derivative for its roots.
+fn cubic_extrema_ts(p0: f64, p1: f64, p2: f64, p3: f64) -> Vec<f64> {
+    let a = 3.0 * (-p0 + 3.0 * p1 - 3.0 * p2 + p3);
+    let b = 6.0 * (p0 - 2.0 * p1 + p2);
+    let c = 3.0 * (p1 - p0);
+    let mut ts = Vec::new();
+    if a.abs() < 1e-9 {
+        if b.abs() > 1e-9 {
+            let t = -c / b;
+            if t > 0.0 && t < 1.0 { ts.push(t); }
+        }
+        return ts;
+    }
+    let disc = b * b - 4.0 * a * c;
+    if disc >= 0.0 {
+        let sq = disc.sqrt();
+        for &t in &[(-b + sq) / (2.0 * a), (-b - sq) / (2.0 * a)] {
+            if t > 0.0 && t < 1.0 { ts.push(t); }
+        }
+    }
+    ts
+}
+
+fn cubic_eval(p0: f64, p1: f64, p2: f64, p3: f64, t: f64) -> f64 {
+    let mt = 1.0 - t;
+    mt * mt * mt * p0 + 3.0 * mt * mt * t * p1 + 3.0 * mt * t * t * p2 + t * t * t * p3
+}
+
+/// An owned, mutable outline built from a borrowed `Outline`'s points, tags
+/// and contours. Unlike `Outline`, it can be transformed in place and fed
+/// back through the decomposition/flattening APIs.
+#[derive(Clone, Debug, Default)]
+pub struct OutlineBuf {
+    points: Vec<Vector>,
+    tags: Vec<c_char>,
+    contours: Vec<c_short>,
+}
+
+impl OutlineBuf {
+    /// Copies a borrowed outline's points, tags and contours into an owned buffer.
+    pub fn from_outline(outline: &Outline) -> Self {
+        OutlineBuf {
+            points: outline.points().to_vec(),
+            tags: outline.tags().to_vec(),
+            contours: outline.contours().to_vec(),
+        }
+    }
+
+    pub fn points(&self) -> &[Vector] {
+        &self.points
+    }
+
+    pub fn tags(&self) -> &[c_char] {
+        &self.tags
+    }
+
+    pub fn contours(&self) -> &[c_short] {
+        &self.contours
+    }
+
+    /// Builds a temporary `FT_Outline` pointing at this buffer's storage, so
+    /// the existing borrowed-outline iteration/decomposition code can be
+    /// reused on owned data. The result borrows from `self` only through
+    /// raw pointers, so keep `self` alive (and don't resize it) for as long
+    /// as the returned value is in use.
+    pub fn as_ft_outline(&self) -> ffi::FT_Outline {
+        ffi::FT_Outline {
+            n_contours: self.contours.len() as c_short,
+            n_points: self.points.len() as c_short,
+            points: self.points.as_ptr() as *mut _,
+            tags: self.tags.as_ptr() as *mut _,
+            contours: self.contours.as_ptr() as *mut _,
+            flags: 0,
+        }
+    }
+
+    /// Streams this buffer's contours to `sink`, same convention as `Outline::decompose`.
+    pub fn decompose<S: OutlineSink>(&self, sink: &mut S) {
+        let raw = self.as_ft_outline();
+        unsafe { Outline::from_raw(&raw) }.decompose(sink);
+    }
+
+    /// Flattens this buffer's contours into polylines, same convention as `Outline::flatten`.
+    pub fn flatten(&self, tolerance: f32) -> Vec<Vec<Vector>> {
+        let raw = self.as_ft_outline();
+        unsafe { Outline::from_raw(&raw) }.flatten(tolerance)
+    }
+
+    /// Applies the affine transform `x' = xx*x + xy*y + tx`, `y' = yx*x + yy*y + ty`
+    /// to every point, matching `FT_Outline_Transform`'s `FT_Matrix` convention.
+    pub fn transform(&mut self, matrix: ffi::FT_Matrix, translate: Vector) {
+        for p in &mut self.points {
+            let (x, y) = (p.x, p.y);
+            p.x = fixed_mul(matrix.xx, x) + fixed_mul(matrix.xy, y) + translate.x;
+            p.y = fixed_mul(matrix.yx, x) + fixed_mul(matrix.yy, y) + translate.y;
+        }
+    }
+
+    /// Translates every point by `delta`.
+    pub fn translate(&mut self, delta: Vector) {
+        for p in &mut self.points {
+            p.x += delta.x;
+            p.y += delta.y;
+        }
+    }
+
+    /// Uniformly scales every point about the origin by `factor` (16.16
+    /// fixed-point).
+    pub fn scale(&mut self, factor: Fixed) {
+        for p in &mut self.points {
+            p.x = fixed_mul(factor, p.x);
+            p.y = fixed_mul(factor, p.y);
+        }
+    }
+
+    /// Reverses the direction of every contour, i.e. flips winding order
+    /// while preserving each contour's first point.
+    pub fn reverse(&mut self) {
+        let ends = self.contours.clone();
+        let mut start = 0usize;
+        for end in ends {
+            let end = end as usize;
+            if end > start {
+                self.reverse_range(start, end);
+            }
+            start = end + 1;
+        }
+    }
+
+    fn reverse_range(&mut self, start: usize, end: usize) {
+        self.points[start + 1..=end].reverse();
+        self.tags[start + 1..=end].reverse();
+    }
+
+    /// Reverses every contour whose current orientation doesn't match `target`.
+    pub fn reverse_to_orientation(&mut self, target: Orientation) {
+        let raw = self.as_ft_outline();
+        let flip: Vec<bool> = unsafe { Outline::from_raw(&raw) }.contours_iter()
+            .map(|curves| curves.orientation() != target)
+            .collect();
+
+        let ends = self.contours.clone();
+        let mut start = 0usize;
+        for (end, flip) in ends.into_iter().zip(flip) {
+            let end = end as usize;
+            if flip && end > start {
+                self.reverse_range(start, end);
+            }
+            start = end + 1;
+        }
+    }
+
+    /// The exact tight bounding box, examining curve extrema rather than
+    /// just the (potentially off-curve) control points.
+    pub fn bounding_box(&self) -> BBox {
+        let raw = self.as_ft_outline();
+        let outline = unsafe { Outline::from_raw(&raw) };
+        let mut bbox = BBox::empty();
+        for curves in outline.contours_iter() {
+            let start = curves.start();
+            bbox.include(start);
+            let mut current = start;
+            for curve in curves {
+                match curve {
+                    Curve::Line(p) => {
+                        bbox.include(p);
+                        current = p;
+                    },
+                    Curve::Bezier2(c, p) => {
+                        bbox.include_quad_extrema(current, c, p);
+                        bbox.include(p);
+                        current = p;
+                    },
+                    Curve::Bezier3(c0, c1, p) => {
+                        bbox.include_cubic_extrema(current, c0, c1, p);
+                        bbox.include(p);
+                        current = p;
+                    },
+                }
+            }
+        }
+        bbox
+    }
+
+    /// A cheaper bounding box over the raw points only, without accounting
+    /// for curves bulging past their control points.
+    pub fn control_box(&self) -> BBox {
+        let mut bbox = BBox::empty();
+        for &p in &self.points {
+            bbox.include(p);
+        }
+        bbox
+    }
+}
+
+/// Outline fixtures shared by this module's tests and `stroke`'s, so both
+/// stay in sync instead of drifting apart as separate copies.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::*;
+
+    // A 4-point 100x100 square, on-curve, single contour, wound
+    // counter-clockwise: (0,0) -> (100,0) -> (100,100) -> (0,100).
+    pub(crate) fn square() -> (Vec<Vector>, Vec<c_char>, Vec<c_short>) {
+        let points = vec![
+            ffi::FT_Vector { x: 0, y: 0 },
+            ffi::FT_Vector { x: 100, y: 0 },
+            ffi::FT_Vector { x: 100, y: 100 },
+            ffi::FT_Vector { x: 0, y: 100 },
+        ];
+        let tags = vec![TAG_ONCURVE; 4];
+        let contours = vec![3];
+        (points, tags, contours)
+    }
+
+    pub(crate) fn raw_outline(points: &[Vector], tags: &[c_char], contours: &[c_short]) -> ffi::FT_Outline {
+        ffi::FT_Outline {
+            n_contours: contours.len() as c_short,
+            n_points: points.len() as c_short,
+            points: points.as_ptr() as *mut _,
+            tags: tags.as_ptr() as *mut _,
+            contours: contours.as_ptr() as *mut _,
+            flags: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::test_support::{ square, raw_outline };
+
+    #[test]
+    fn flatten_closes_each_polyline_with_a_duplicate_first_point() {
+        let (points, tags, contours) = square();
+        let raw = raw_outline(&points, &tags, &contours);
+        let outline = unsafe { Outline::from_raw(&raw) };
+
+        let polys = outline.flatten(1.0);
+        assert_eq!(polys.len(), 1);
+        let poly = &polys[0];
+        assert_eq!(poly.len(), 5);
+        assert_eq!((poly[0].x, poly[0].y), (poly[4].x, poly[4].y));
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        MoveTo(Vector),
+        LineTo(Vector),
+        QuadTo(Vector, Vector),
+        CubicTo(Vector, Vector, Vector),
+        Close,
+    }
+
+    #[derive(Default)]
+    struct RecordingSink {
+        events: Vec<Event>,
+    }
+
+    impl OutlineSink for RecordingSink {
+        fn move_to(&mut self, p: Vector) { self.events.push(Event::MoveTo(p)); }
+        fn line_to(&mut self, p: Vector) { self.events.push(Event::LineTo(p)); }
+        fn quad_to(&mut self, ctrl: Vector, p: Vector) { self.events.push(Event::QuadTo(ctrl, p)); }
+        fn cubic_to(&mut self, c0: Vector, c1: Vector, p: Vector) { self.events.push(Event::CubicTo(c0, c1, p)); }
+        fn close(&mut self) { self.events.push(Event::Close); }
+    }
+
+    fn v(x: c_short, y: c_short) -> Vector {
+        ffi::FT_Vector { x: x as _, y: y as _ }
+    }
+
+    #[test]
+    fn decompose_streams_mixed_on_and_off_curve_points() {
+        // A single contour: on-curve start, off-curve control, on-curve end,
+        // implicitly closed back to the start.
+        let points = vec![v(0, 0), v(50, 100), v(100, 0)];
+        let tags = vec![TAG_ONCURVE, TAG_BEZIER2, TAG_ONCURVE];
+        let contours = vec![2];
+        let raw = raw_outline(&points, &tags, &contours);
+        let outline = unsafe { Outline::from_raw(&raw) };
+
+        let mut sink = RecordingSink::default();
+        outline.decompose(&mut sink);
+
+        assert_eq!(sink.events, vec![
+            Event::MoveTo(points[0]),
+            Event::QuadTo(points[1], points[2]),
+            Event::LineTo(points[0]),
+            Event::Close,
+        ]);
+    }
+
+    #[test]
+    fn signed_area_sign_matches_winding_direction() {
+        let (points, tags, contours) = square();
+        let raw = raw_outline(&points, &tags, &contours);
+        let outline = unsafe { Outline::from_raw(&raw) };
+        let curves = outline.contours_iter().next().unwrap();
+
+        // (0,0) -> (100,0) -> (100,100) -> (0,100) is counter-clockwise.
+        assert!(curves.signed_area() > 0.0);
+        assert_eq!(curves.orientation(), Orientation::CounterClockwise);
+    }
+
+    fn square_buf() -> OutlineBuf {
+        let (points, tags, contours) = square();
+        let raw = raw_outline(&points, &tags, &contours);
+        OutlineBuf::from_outline(&unsafe { Outline::from_raw(&raw) })
+    }
+
+    // 16.16 fixed-point conversion for FT_Matrix/scale factors in tests.
+    fn fixed(v: f64) -> Fixed {
+        (v * 65536.0).round() as Fixed
+    }
+
+    #[test]
+    fn transform_rotates_and_translates_points() {
+        let mut buf = square_buf();
+
+        // 90-degree counter-clockwise rotation: x' = -y, y' = x.
+        let matrix = ffi::FT_Matrix {
+            xx: fixed(0.0), xy: fixed(-1.0),
+            yx: fixed(1.0), yy: fixed(0.0),
+        };
+        buf.transform(matrix, ffi::FT_Vector { x: 10, y: 20 });
+
+        assert_eq!(buf.points(), &[
+            ffi::FT_Vector { x: 10, y: 20 },
+            ffi::FT_Vector { x: 10, y: 120 },
+            ffi::FT_Vector { x: -90, y: 120 },
+            ffi::FT_Vector { x: -90, y: 20 },
+        ]);
+    }
+
+    #[test]
+    fn translate_shifts_every_point() {
+        let mut buf = square_buf();
+        buf.translate(ffi::FT_Vector { x: 5, y: -5 });
+
+        assert_eq!(buf.points(), &[
+            ffi::FT_Vector { x: 5, y: -5 },
+            ffi::FT_Vector { x: 105, y: -5 },
+            ffi::FT_Vector { x: 105, y: 95 },
+            ffi::FT_Vector { x: 5, y: 95 },
+        ]);
+    }
+
+    #[test]
+    fn scale_multiplies_every_point() {
+        let mut buf = square_buf();
+        buf.scale(fixed(2.0));
+
+        assert_eq!(buf.points(), &[
+            ffi::FT_Vector { x: 0, y: 0 },
+            ffi::FT_Vector { x: 200, y: 0 },
+            ffi::FT_Vector { x: 200, y: 200 },
+            ffi::FT_Vector { x: 0, y: 200 },
+        ]);
+    }
+
+    #[test]
+    fn reverse_keeps_first_point_and_flips_winding() {
+        let mut buf = square_buf();
+        buf.reverse();
+
+        // First point is preserved; the rest come back in reverse order.
+        assert_eq!(buf.points(), &[
+            ffi::FT_Vector { x: 0, y: 0 },
+            ffi::FT_Vector { x: 0, y: 100 },
+            ffi::FT_Vector { x: 100, y: 100 },
+            ffi::FT_Vector { x: 100, y: 0 },
+        ]);
+
+        let raw = buf.as_ft_outline();
+        let outline = unsafe { Outline::from_raw(&raw) };
+        let curves = outline.contours_iter().next().unwrap();
+        assert_eq!(curves.orientation(), Orientation::Clockwise);
+    }
+
+    #[test]
+    fn reverse_to_orientation_only_flips_mismatched_contours() {
+        let mut buf = square_buf();
+
+        // Already counter-clockwise: asking for counter-clockwise is a no-op.
+        buf.reverse_to_orientation(Orientation::CounterClockwise);
+        assert_eq!(buf.points(), square_buf().points());
+
+        buf.reverse_to_orientation(Orientation::Clockwise);
+        let raw = buf.as_ft_outline();
+        let outline = unsafe { Outline::from_raw(&raw) };
+        let curves = outline.contours_iter().next().unwrap();
+        assert_eq!(curves.orientation(), Orientation::Clockwise);
+    }
+
+    #[test]
+    fn bounding_box_and_control_box_match_for_a_straight_sided_contour() {
+        let buf = square_buf();
+
+        let bbox = buf.bounding_box();
+        assert_eq!((bbox.xMin, bbox.yMin, bbox.xMax, bbox.yMax), (0, 0, 100, 100));
+        assert_eq!(buf.control_box(), bbox);
+    }
+}
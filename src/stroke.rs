@@ -0,0 +1,220 @@
+//! Outline stroking implemented in pure Rust: turns a path plus a pen width
+//! into a fillable outline, without going through FreeType's `FT_Stroker`
+//! FFI. See the `stroker` module for the FFI-backed alternative that drives
+//! `FT_Stroker` directly; reach for this module when an owned, fillable
+//! outline is wanted without a `Library` handle.
+//!
+//! Each contour is first flattened to line segments (`outline::Outline::flatten`),
+//! then offset to either side by `width / 2` along the segment normals. The
+//! two offset sides are joined per `StrokeStyle::line_join` and emitted as a
+//! single closed contour (left side forward, right side reversed) so it
+//! fills correctly under the nonzero winding rule.
+
+use std::f64::consts::PI;
+use ffi;
+use Vector;
+use outline::{ Outline, OutlineSink };
+
+/// How two consecutive segments are connected.
+///
+/// Distinct from (but mirrors the vocabulary of) `stroker::StrokerLineJoin`,
+/// which configures `FT_Stroker` instead of this module's own offsetting.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineJoin {
+    /// Segments are connected by extending their outer edges until they
+    /// meet, falling back to a bevel when that point is farther than
+    /// `limit` times the half-width from the joint.
+    Miter(f32),
+    /// Segments are connected by a straight edge.
+    Bevel,
+    /// Segments are connected by an arc.
+    Round,
+}
+
+/// Pen parameters used to stroke an outline.
+///
+/// There is no line cap setting: every contour this module strokes comes
+/// from `Outline`, whose FreeType-sourced contours are always closed, so
+/// there are no open ends to cap. Add one if/when an open-path input is
+/// supported.
+#[derive(Copy, Clone, Debug)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub line_join: LineJoin,
+}
+
+/// An owned, already-flattened outline produced by the stroker: one polygon
+/// per stroked contour, ready to be filled with the nonzero winding rule.
+pub struct StrokeOutline {
+    contours: Vec<Vec<Vector>>,
+}
+
+impl StrokeOutline {
+    /// The stroked polygons, one per input contour.
+    pub fn contours(&self) -> &[Vec<Vector>] {
+        &self.contours
+    }
+
+    /// Streams the stroked polygons to `sink`, same convention as
+    /// `Outline::decompose`.
+    pub fn decompose<S: OutlineSink>(&self, sink: &mut S) {
+        for contour in &self.contours {
+            if let Some(&first) = contour.first() {
+                sink.move_to(first);
+                for &p in &contour[1..] {
+                    sink.line_to(p);
+                }
+                sink.close();
+            }
+        }
+    }
+}
+
+/// Strokes every contour of `outline` with `style`, flattening curves to
+/// within `tolerance` font units before offsetting.
+pub fn stroke_outline(outline: &Outline, style: &StrokeStyle, tolerance: f32) -> StrokeOutline {
+    let half_width = (style.width as f64) / 2.0;
+    let contours = outline.flatten(tolerance).into_iter()
+        .map(|points| stroke_closed_polyline(&points, half_width, style.line_join))
+        .collect();
+    StrokeOutline { contours: contours }
+}
+
+#[inline]
+fn to_f64(p: Vector) -> (f64, f64) {
+    (p.x as f64, p.y as f64)
+}
+
+#[inline]
+fn to_vector(x: f64, y: f64) -> Vector {
+    ffi::FT_Vector { x: x.round() as _, y: y.round() as _ }
+}
+
+#[inline]
+fn unit_normal(a: Vector, b: Vector) -> (f64, f64) {
+    let (ax, ay) = to_f64(a);
+    let (bx, by) = to_f64(b);
+    let (dx, dy) = (bx - ax, by - ay);
+    let len = (dx * dx + dy * dy).sqrt();
+    if len == 0.0 {
+        (0.0, 0.0)
+    } else {
+        (-dy / len, dx / len)
+    }
+}
+
+#[inline]
+fn offset(p: Vector, normal: (f64, f64), d: f64) -> (f64, f64) {
+    let (x, y) = to_f64(p);
+    (x + normal.0 * d, y + normal.1 * d)
+}
+
+/// Offsets a closed polyline by `d` along its segment normals (`d` may be
+/// negative to offset to the other side), inserting join geometry at each
+/// vertex per `join`.
+fn offset_side(points: &[Vector], d: f64, join: LineJoin) -> Vec<(f64, f64)> {
+    let n = points.len();
+    let mut out = Vec::with_capacity(n * 2);
+    for i in 0..n {
+        let a = points[i];
+        let b = points[(i + 1) % n];
+        let normal = unit_normal(a, b);
+        out.push(offset(a, normal, d));
+        out.push(offset(b, normal, d));
+
+        let c = points[(i + 2) % n];
+        let next_normal = unit_normal(b, c);
+        join_corner(&mut out, b, normal, next_normal, d, join);
+    }
+    out
+}
+
+/// Inserts the join geometry needed at a vertex where the outline turns
+/// away from the offset side (an outer corner); inner corners are already
+/// covered by the overlapping segment offsets.
+fn join_corner(out: &mut Vec<(f64, f64)>, p: Vector, n0: (f64, f64), n1: (f64, f64), d: f64, join: LineJoin) {
+    let cross = n0.0 * n1.1 - n0.1 * n1.0;
+    if cross * d >= 0.0 {
+        // Inner corner: the segment offsets already overlap correctly.
+        return;
+    }
+    match join {
+        LineJoin::Bevel => {
+            // The two segment endpoints already form the bevel.
+        },
+        LineJoin::Miter(limit) => {
+            let dot = n0.0 * n1.0 + n0.1 * n1.1;
+            let half_cos = ((1.0 + dot) / 2.0).max(0.0).sqrt();
+            let miter_len = if half_cos > 1e-6 { 1.0 / half_cos } else { f64::MAX };
+            if miter_len <= limit as f64 {
+                let (bx, by) = (n0.0 + n1.0, n0.1 + n1.1);
+                let blen = (bx * bx + by * by).sqrt();
+                if blen > 1e-9 {
+                    out.push(offset(p, (bx / blen, by / blen), d * miter_len));
+                }
+            }
+        },
+        LineJoin::Round => {
+            let steps = 4;
+            let a0 = n0.1.atan2(n0.0);
+            let a1 = n1.1.atan2(n1.0);
+            let mut delta = a1 - a0;
+            while delta > PI { delta -= 2.0 * PI; }
+            while delta < -PI { delta += 2.0 * PI; }
+            for s in 1..steps {
+                let a = a0 + delta * (s as f64 / steps as f64);
+                out.push(offset(p, (a.cos(), a.sin()), d));
+            }
+        },
+    }
+}
+
+// `Outline::flatten` closes each polyline explicitly, repeating its first
+// point as its last (see `outline::FlattenIterator`). `offset_side` already
+// wraps around via `% n`, so that trailing duplicate must be stripped first
+// or the wraparound segment degenerates to zero length and pinches the seam.
+fn dedup_closing_point(points: &[Vector]) -> &[Vector] {
+    match (points.first(), points.split_last()) {
+        (Some(&first), Some((&last, rest))) if first.x == last.x && first.y == last.y => rest,
+        _ => points,
+    }
+}
+
+fn stroke_closed_polyline(points: &[Vector], half_width: f64, join: LineJoin) -> Vec<Vector> {
+    let points = dedup_closing_point(points);
+    if points.len() < 2 {
+        return Vec::new();
+    }
+    let left = offset_side(points, half_width, join);
+    let mut right = offset_side(points, -half_width, join);
+    right.reverse();
+
+    left.into_iter().chain(right)
+        .map(|(x, y)| to_vector(x, y))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use outline::Outline;
+    use outline::test_support::{ square, raw_outline };
+
+    #[test]
+    fn stroked_square_seam_has_no_coincident_points() {
+        let (points, tags, contours) = square();
+        let raw = raw_outline(&points, &tags, &contours);
+        let outline = unsafe { Outline::from_raw(&raw) };
+        let style = StrokeStyle { width: 10.0, line_join: LineJoin::Miter(4.0) };
+
+        let stroked = stroke_outline(&outline, &style, 1.0);
+        let contour = &stroked.contours()[0];
+        let n = contour.len();
+        for i in 0..n {
+            let a = contour[i];
+            let b = contour[(i + 1) % n];
+            assert!(a.x != b.x || a.y != b.y,
+                    "coincident consecutive points at index {} (seam pinch)", i);
+        }
+    }
+}